@@ -46,12 +46,18 @@ pub enum VisualPanicLevel {
 /// Because all fields are optional to set, each one is wrapped in an [`Option<T>`].
 #[derive(Clone)]
 pub struct VisualPanic {
-    /// <div class="warning">Currently not implemented!</div>
     /// Option to set a custom icon to be used.
-    /// Value must be set to a valid path, e.g.,
+    /// Value must be set to a valid path pointing to a `.png`, `.ico`, `.bmp` or
+    /// `.jpg`/`.jpeg` file, e.g.,
     /// ```rust
     /// Some(String::from("path/to/icon.png"));
     /// ```
+    /// The path is validated in [`VisualPanic::register_global`] (logged and
+    /// cleared, rather than panicking, if it does not exist or has an
+    /// unsupported format). <div class="warning">`native_dialog::MessageDialog`
+    /// has no API to display a custom bitmap icon, so this is validated but not
+    /// actually rendered.</div> The dialog's icon is always the OS-native one
+    /// for the resolved [`VisualPanicLevel`], set via [`VisualPanicLevel`].
     custom_icon: Option<String>,
     /// Option to set a custom title to be used.
     /// Value can be set to any UTF-8 compliant [`String`], e.g.,
@@ -66,6 +72,143 @@ pub struct VisualPanic {
     /// Some(VisualPanicLevel::Error);
     /// ```
     custom_level: Option<VisualPanicLevel>,
+    /// Option to capture and append a [`std::backtrace::Backtrace`] to the dialog.
+    /// Still honors `RUST_BACKTRACE`/`RUST_LIB_BACKTRACE`: the backtrace is only
+    /// actually captured when the environment enables it, exactly like the
+    /// default panic printer. Set via [`VisualPanic::with_show_backtrace`].
+    show_backtrace: Option<bool>,
+    /// Option to cap how many backtrace frames are rendered in the dialog.
+    /// Defaults to [`DEFAULT_MAX_BACKTRACE_FRAMES`] when unset. Set via
+    /// [`VisualPanic::with_max_backtrace_frames`].
+    max_backtrace_frames: Option<usize>,
+    /// Option to invoke the previously installed panic hook (if any) instead of
+    /// silently discarding it. Set via [`VisualPanic::with_chain_previous`].
+    chain_previous: Option<bool>,
+    /// Option to show a confirm dialog with Yes/No buttons instead of a single
+    /// acknowledgement button, letting the user choose between attempting to
+    /// resume ("Yes") and aborting the process immediately ("No").
+    /// Set via [`VisualPanic::with_allow_resume`].
+    allow_resume: Option<bool>,
+    /// Option to write the full panic report (location, payload, message and
+    /// optional backtrace) to a timestamped file in this directory and report
+    /// its path in the dialog. Set via [`VisualPanic::with_report_file`].
+    report_dir: Option<String>,
+}
+
+/// Holds the panic hook that was installed before [`VisualPanic::register_global`]
+/// replaced it, so [`VisualPanic::unregister`] can restore it later.
+static PREVIOUS_HOOK: std::sync::OnceLock<std::sync::Mutex<Option<std::sync::Arc<dyn Fn(&std::panic::PanicInfo) + Sync + Send>>>> = std::sync::OnceLock::new();
+
+/// Default number of backtrace frames rendered in the dialog when
+/// [`VisualPanic::show_backtrace`](VisualPanic) is enabled and no explicit
+/// limit was set via [`VisualPanic::with_max_backtrace_frames`].
+const DEFAULT_MAX_BACKTRACE_FRAMES: usize = 20;
+
+/// Formats a captured [`std::backtrace::Backtrace`], truncating it to at most
+/// `max_frames` frames so a deep backtrace does not blow up the dialog size.
+fn format_backtrace(backtrace: &std::backtrace::Backtrace, max_frames: usize) -> String {
+    let full = backtrace.to_string();
+    let is_frame_header = |line: &str| {
+        let trimmed = line.trim_start();
+        trimmed.chars().next().map_or(false, |c| c.is_ascii_digit()) && trimmed.contains(':')
+    };
+    let total_frames = full.lines().filter(|line| is_frame_header(line)).count();
+
+    let mut kept_lines: Vec<&str> = Vec::new();
+    let mut frame_count = 0usize;
+    for line in full.lines() {
+        if is_frame_header(line) {
+            frame_count += 1;
+            if frame_count > max_frames {
+                break;
+            }
+        }
+        kept_lines.push(line);
+    }
+
+    let mut result = kept_lines.join("\n");
+    if total_frames > max_frames {
+        result.push_str(&format!("\n... {} more frame(s) omitted ...", total_frames - max_frames));
+    }
+    result
+}
+
+/// Image extensions accepted for [`VisualPanic::custom_icon`].
+const SUPPORTED_ICON_EXTENSIONS: [&str; 5] = ["png", "ico", "bmp", "jpg", "jpeg"];
+
+/// Validates that `path` exists and has a supported image extension.
+/// Returns a human-readable error instead of panicking so a bad icon path
+/// never suppresses the actual panic report.
+fn validate_icon_path(path: &str) -> Result<(), String> {
+    let path = std::path::Path::new(path);
+    if !path.exists() {
+        return Err(format!("Icon file does not exist: {}", path.display()));
+    }
+    let has_supported_extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| SUPPORTED_ICON_EXTENSIONS.contains(&extension.to_ascii_lowercase().as_str()))
+        .unwrap_or(false);
+    if !has_supported_extension {
+        return Err(format!("Unsupported icon format: {}", path.display()));
+    }
+    Ok(())
+}
+
+/// Renders an [`Error`](std::error::Error) and its `source()` chain, one cause
+/// per line, so panicking with a rich error object shows the full context
+/// instead of just its top-level `Display` output.
+fn format_error_chain(error: &(dyn std::error::Error + 'static)) -> String {
+    let mut lines = vec![error.to_string()];
+    let mut source = error.source();
+    while let Some(cause) = source {
+        lines.push(format!("Caused by: {}", cause));
+        source = cause.source();
+    }
+    lines.join("\n")
+}
+
+/// Recovers a human-readable message from a panic payload.
+/// Tries `Error`-typed payloads first (rendering the full `source()` chain),
+/// then [`&str`], then [`String`], and finally falls back to a generic message
+/// for any other `Any + Send` payload, so the dialog can always be shown
+/// instead of double-panicking while unwrapping an unexpected payload type.
+fn extract_payload_text(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(error) = payload.downcast_ref::<Box<dyn std::error::Error + Send + Sync>>() {
+        format_error_chain(error.as_ref())
+    } else if let Some(error) = payload.downcast_ref::<Box<dyn std::error::Error + Send>>() {
+        format_error_chain(error.as_ref())
+    } else if let Some(error) = payload.downcast_ref::<&(dyn std::error::Error + Send)>() {
+        format_error_chain(*error)
+    } else if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("Box<dyn Any> (non-string payload)")
+    }
+}
+
+/// Assembles the structured panic report (location, payload, message and
+/// optional backtrace) shared by the dialog body and the report file, so both
+/// stay in sync.
+fn build_panic_report(location_str: &str, payload_as_str: &str, message: &str, backtrace_section: Option<&str>) -> String {
+    let mut report = format!("An error occured.\n({})\n\nPayload: {}\n\nMessage: {}\n", location_str, payload_as_str, message);
+    if let Some(backtrace_section) = backtrace_section {
+        report.push_str(&format!("\nBacktrace:\n{}\n", backtrace_section));
+    }
+    report
+}
+
+/// Writes `report` to a timestamped file inside `dir` and returns its path.
+fn write_report_file(dir: &str, report: &str) -> std::io::Result<std::path::PathBuf> {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let path = std::path::Path::new(dir).join(format!("visualpanic-report-{timestamp}.txt"));
+    std::fs::write(&path, report)?;
+    Ok(path)
 }
 
 /// Provide public methods for [`VisualPanic`].
@@ -76,6 +219,11 @@ impl VisualPanic {
             custom_icon: None,
             custom_title: None,
             custom_level: None,
+            show_backtrace: None,
+            max_backtrace_frames: None,
+            chain_previous: None,
+            allow_resume: None,
+            report_dir: None,
         }
     }
 
@@ -94,6 +242,11 @@ impl VisualPanic {
             custom_icon: None,
             custom_title: None,
             custom_level: None,
+            show_backtrace: None,
+            max_backtrace_frames: None,
+            chain_previous: None,
+            allow_resume: None,
+            report_dir: None,
         };
         if let Some(icon_str) = custom_icon {
             return_val.custom_icon = Some(String::from(icon_str));
@@ -107,22 +260,135 @@ impl VisualPanic {
         return return_val;
     }
 
+    /// Enables or disables capturing a backtrace in the panic dialog.
+    /// The backtrace is still only actually captured when `RUST_BACKTRACE`
+    /// (or `RUST_LIB_BACKTRACE`) enables it, e.g.,
+    /// ```rust
+    /// # use visualpanic_rs::VisualPanic;
+    /// let visual_panic_options: VisualPanic = VisualPanic::default().with_show_backtrace(true);
+    /// ```
+    pub fn with_show_backtrace(mut self, show_backtrace: bool) -> Self {
+        self.show_backtrace = Some(show_backtrace);
+        return self;
+    }
+
+    /// Sets how many backtrace frames are rendered in the dialog before being
+    /// truncated. Only takes effect when [`VisualPanic::with_show_backtrace`]
+    /// is enabled, e.g.,
+    /// ```rust
+    /// # use visualpanic_rs::VisualPanic;
+    /// let visual_panic_options: VisualPanic = VisualPanic::default()
+    ///     .with_show_backtrace(true)
+    ///     .with_max_backtrace_frames(10);
+    /// ```
+    pub fn with_max_backtrace_frames(mut self, max_backtrace_frames: usize) -> Self {
+        self.max_backtrace_frames = Some(max_backtrace_frames);
+        return self;
+    }
+
+    /// Enables chaining the panic hook that was installed before
+    /// [`VisualPanic::register_global`], e.g., a logging hook or `human-panic`,
+    /// instead of silently discarding it. The previous hook is invoked before
+    /// the VisualPanic dialog is shown, e.g.,
+    /// ```rust
+    /// # use visualpanic_rs::VisualPanic;
+    /// let visual_panic_options: VisualPanic = VisualPanic::default().with_chain_previous(true);
+    /// ```
+    pub fn with_chain_previous(mut self, chain_previous: bool) -> Self {
+        self.chain_previous = Some(chain_previous);
+        return self;
+    }
+
+    /// Shows a confirm dialog with "Yes" and "No" buttons instead of a
+    /// single acknowledgement button. Choosing "No" terminates the process
+    /// immediately; choosing "Yes" lets the unwind proceed normally, e.g.,
+    /// ```rust
+    /// # use visualpanic_rs::VisualPanic;
+    /// let visual_panic_options: VisualPanic = VisualPanic::default().with_allow_resume(true);
+    /// ```
+    pub fn with_allow_resume(mut self, allow_resume: bool) -> Self {
+        self.allow_resume = Some(allow_resume);
+        return self;
+    }
+
+    /// Enables writing the full panic report to a timestamped file inside
+    /// `dir`, and reports the resulting path in the dialog, e.g.,
+    /// ```rust
+    /// # use visualpanic_rs::VisualPanic;
+    /// let visual_panic_options: VisualPanic = VisualPanic::default().with_report_file("/tmp");
+    /// ```
+    pub fn with_report_file(mut self, dir: &str) -> Self {
+        self.report_dir = Some(String::from(dir));
+        return self;
+    }
+
     /// Registers a [`VisualPanic`] globally, i.e., for the whole application.
     /// Returns currently nothing.
-    /// Will panic, if handling the &[`PanicInfo`] fails in any way or the native message dialog can not be spawned.
+    /// The installed hook panics, which aborts the process, if the native
+    /// message dialog can not be spawned.
     pub fn register_global(self) {
 
-        let clone = self.clone();
+        let mut clone = self.clone();
+
+        if let Some(icon_path) = &clone.custom_icon {
+            if let Err(error) = validate_icon_path(icon_path) {
+                eprintln!("[visualpanic-rs] {error} — continuing without a custom icon.");
+                clone.custom_icon = None;
+            }
+        }
+
+        if clone.chain_previous.unwrap_or(false) {
+            let previous_hook: std::sync::Arc<dyn Fn(&std::panic::PanicInfo) + Sync + Send> = std::sync::Arc::from(std::panic::take_hook());
+            let hook_slot = PREVIOUS_HOOK.get_or_init(|| std::sync::Mutex::new(None));
+            *hook_slot.lock().unwrap() = Some(previous_hook);
+        }
 
         std::panic::set_hook(Box::new(move |panic_info| {
+            if clone.chain_previous.unwrap_or(false) {
+                let previous_hook = PREVIOUS_HOOK.get().and_then(|hook_slot| hook_slot.lock().unwrap().clone());
+                if let Some(previous_hook) = previous_hook {
+                    // Run outside the lock, so the chained hook never runs while
+                    // our lock is held. A panic raised from within a panic hook
+                    // makes Rust abort the process immediately, so `catch_unwind`
+                    // cannot intercept it and the log line below is not reached
+                    // in that case.
+                    if std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| previous_hook(panic_info))).is_err() {
+                        eprintln!("[visualpanic-rs] The chained panic hook panicked; continuing with the VisualPanic dialog.");
+                    }
+                }
+            }
+
             let mut level: native_dialog::MessageType = native_dialog::MessageType::Error;
-            let mut icon: Option<String> = None;
             let mut title = String::from(env!("CARGO_PKG_NAME"));
-            let payload_as_str = panic_info.payload().downcast_ref::<&str>().expect("Failed to extract Panic Payload to &str.");
-            let location = panic_info.location().expect("Failed to get location where the panic occured.");
-            let message = panic_info.message().expect("Failed to extract message.").to_string();
-            let location_str = format!("File: {} at Line: {}, Column: {}", location.file(), location.line(), location.column());
-            let display_message = format!("An error occured.\n({})\n\nPayload: {}\n\nMessage: {}\n\nThe program will terminate.\n", location_str, payload_as_str, message);
+            let payload_as_str = extract_payload_text(panic_info.payload());
+            let location_str = match panic_info.location() {
+                Some(location) => format!("File: {} at Line: {}, Column: {}", location.file(), location.line(), location.column()),
+                None => String::from("<unknown location>"),
+            };
+            let message = match panic_info.message() {
+                Some(message) => message.to_string(),
+                None => payload_as_str.clone(),
+            };
+            let backtrace_str = if clone.show_backtrace.unwrap_or(false) {
+                let backtrace = std::backtrace::Backtrace::capture();
+                if backtrace.status() == std::backtrace::BacktraceStatus::Captured {
+                    let max_frames = clone.max_backtrace_frames.unwrap_or(DEFAULT_MAX_BACKTRACE_FRAMES);
+                    Some(format_backtrace(&backtrace, max_frames))
+                } else {
+                    None
+                }
+            } else {
+                None
+            };
+            let report = build_panic_report(&location_str, &payload_as_str, &message, backtrace_str.as_deref());
+            let mut display_message = format!("{report}\nThe program will terminate.\n");
+
+            if let Some(report_dir) = &clone.report_dir {
+                match write_report_file(report_dir, &report) {
+                    Ok(path) => display_message.push_str(&format!("\nFull report written to: {}\n", path.display())),
+                    Err(error) => eprintln!("[visualpanic-rs] Failed to write panic report to {report_dir}: {error}"),
+                }
+            }
 
             match &clone.custom_title {
                 None => {},
@@ -138,29 +404,184 @@ impl VisualPanic {
                     }
                 }
             }
-            match &clone.custom_icon {
-                None => {}
-                Some(custom) => { icon = Some(custom.clone()) }
-            }
+            // `native_dialog::MessageDialog` has no API to render `custom_icon` as a
+            // custom bitmap; the dialog's icon is always the OS-native one for `level`.
 
-            let _ = native_dialog::MessageDialog::new()
-                .set_title(&title)
-                .set_type(level)
-                .set_text(&display_message)
-                .show_alert()
-                .expect("Failed to launch VisualPanic Dialog.");
+            if clone.allow_resume.unwrap_or(false) {
+                display_message.push_str("\nChoose \"Yes\" to attempt to resume, or \"No\" to abort immediately.\n");
+                let wants_to_resume = native_dialog::MessageDialog::new()
+                    .set_title(&title)
+                    .set_type(level)
+                    .set_text(&display_message)
+                    .show_confirm()
+                    .expect("Failed to launch VisualPanic Dialog.");
+                if !wants_to_resume {
+                    std::process::abort();
+                }
+            } else {
+                let _ = native_dialog::MessageDialog::new()
+                    .set_title(&title)
+                    .set_type(level)
+                    .set_text(&display_message)
+                    .show_alert()
+                    .expect("Failed to launch VisualPanic Dialog.");
+            }
         }));
     }
+
+    /// Restores the panic hook that was saved by [`VisualPanic::register_global`]
+    /// when `chain_previous` was enabled, e.g., to temporarily enable visual
+    /// panics only while a window is open. Falls back to the Rust default hook
+    /// if no hook was saved.
+    pub fn unregister() {
+        if let Some(hook_slot) = PREVIOUS_HOOK.get() {
+            if let Some(previous_hook) = hook_slot.lock().unwrap().take() {
+                std::panic::set_hook(Box::new(move |panic_info| previous_hook(panic_info)));
+                return;
+            }
+        }
+        let _ = std::panic::take_hook();
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // `std::panic::set_hook` is process-global, so a test that calls
+    // `register_global()` and then actually panics can abort the whole
+    // `cargo test` binary (e.g. when no `zenity`/`kdialog`/display is present,
+    // `native_dialog` returns an error, `.expect()` panics *inside the panic
+    // hook*, and Rust guarantees that aborts the process) or, with a display
+    // present, block forever on a real dialog click. The tests below that do
+    // this are `#[ignore]`d for that reason; run them individually and interactively
+    // with `cargo test -- --ignored <name>` to eyeball the dialog.
+
     #[test]
+    #[ignore = "installs a global panic hook and shows a real dialog; run interactively"]
     fn show_error() {
         VisualPanic::new(None, Some("VisualPanic v1.0"), Some(VisualPanicLevel::Warning)).register_global();
         let num1: i32 = 2;
         num1.checked_div(0).unwrap();
     }
+
+    #[test]
+    #[ignore = "installs a global panic hook and shows a real dialog; run interactively"]
+    fn show_error_string_payload() {
+        VisualPanic::new(None, Some("VisualPanic v1.0"), Some(VisualPanicLevel::Warning)).register_global();
+        let reason = String::from("formatted failure");
+        panic!("{}", reason);
+    }
+
+    #[test]
+    #[ignore = "installs a global panic hook and shows a real dialog; run interactively"]
+    fn show_error_with_backtrace() {
+        std::env::set_var("RUST_BACKTRACE", "full");
+        VisualPanic::default()
+            .with_show_backtrace(true)
+            .with_max_backtrace_frames(5)
+            .register_global();
+        panic!("panic with a truncated backtrace attached");
+    }
+
+    #[test]
+    #[ignore = "installs a global panic hook and shows a real dialog; run interactively"]
+    fn chains_into_previous_hook() {
+        static PREVIOUS_HOOK_RAN: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+        std::panic::set_hook(Box::new(|_| {
+            PREVIOUS_HOOK_RAN.store(true, std::sync::atomic::Ordering::SeqCst);
+        }));
+
+        VisualPanic::default().with_chain_previous(true).register_global();
+        let result = std::panic::catch_unwind(|| {
+            panic!("chained panic");
+        });
+        assert!(result.is_err());
+        assert!(PREVIOUS_HOOK_RAN.load(std::sync::atomic::Ordering::SeqCst));
+
+        VisualPanic::unregister();
+    }
+
+    #[test]
+    fn validate_icon_path_rejects_missing_and_unsupported_files() {
+        assert!(validate_icon_path("does/not/exist.png").is_err());
+
+        let unsupported = std::env::temp_dir().join("visualpanic_rs_test_icon.txt");
+        std::fs::write(&unsupported, b"not an image").unwrap();
+        assert!(validate_icon_path(unsupported.to_str().unwrap()).is_err());
+        std::fs::remove_file(&unsupported).unwrap();
+
+        let supported = std::env::temp_dir().join("visualpanic_rs_test_icon.png");
+        std::fs::write(&supported, b"not actually a png, just a valid path").unwrap();
+        assert!(validate_icon_path(supported.to_str().unwrap()).is_ok());
+        std::fs::remove_file(&supported).unwrap();
+    }
+
+    #[test]
+    #[ignore = "installs a global panic hook and shows a real dialog; run interactively"]
+    fn show_error_with_missing_custom_icon_falls_back() {
+        VisualPanic::new(Some("does/not/exist.png"), Some("VisualPanic v1.0"), Some(VisualPanicLevel::Error))
+            .register_global();
+        panic!("panic with an invalid custom icon path");
+    }
+
+    #[derive(Debug)]
+    struct RootCause;
+
+    impl std::fmt::Display for RootCause {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "the underlying root cause")
+        }
+    }
+
+    impl std::error::Error for RootCause {}
+
+    #[derive(Debug)]
+    struct WrappingError;
+
+    impl std::fmt::Display for WrappingError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "a wrapping error occured")
+        }
+    }
+
+    impl std::error::Error for WrappingError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&RootCause)
+        }
+    }
+
+    #[test]
+    fn formats_error_payload_source_chain() {
+        let formatted = format_error_chain(&WrappingError);
+        assert_eq!(formatted, "a wrapping error occured\nCaused by: the underlying root cause");
+    }
+
+    #[test]
+    #[ignore = "installs a global panic hook and shows a real dialog; run interactively"]
+    fn show_error_with_error_payload() {
+        VisualPanic::new(None, Some("VisualPanic v1.0"), Some(VisualPanicLevel::Error)).register_global();
+        let error: Box<dyn std::error::Error + Send + Sync> = Box::new(WrappingError);
+        std::panic::panic_any(error);
+    }
+
+    #[test]
+    fn writes_report_file_with_shared_formatting() {
+        let report = build_panic_report("File: test.rs at Line: 1, Column: 1", "payload", "message", None);
+        let dir = std::env::temp_dir();
+        let path = write_report_file(dir.to_str().unwrap(), &report).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents, report);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    #[ignore = "installs a global panic hook and blocks on a real confirm dialog; run interactively"]
+    fn show_error_with_report_file_and_resume_option() {
+        VisualPanic::new(None, Some("VisualPanic v1.0"), Some(VisualPanicLevel::Warning))
+            .with_allow_resume(true)
+            .with_report_file(std::env::temp_dir().to_str().unwrap())
+            .register_global();
+        panic!("panic with actionable buttons and a report file");
+    }
 }